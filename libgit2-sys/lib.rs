@@ -0,0 +1,90 @@
+#![allow(non_camel_case_types)]
+
+extern crate libc;
+
+use libc::{c_int, c_uint, c_char, size_t};
+
+pub const GIT_OID_RAWSZ: usize = 20;
+
+#[repr(C)]
+pub struct git_oid {
+    pub id: [u8; GIT_OID_RAWSZ],
+}
+
+#[repr(C)]
+pub struct git_buf {
+    pub ptr: *mut c_char,
+    pub asize: size_t,
+    pub size: size_t,
+}
+
+pub enum git_repository {}
+pub enum git_commit {}
+pub enum git_tree {}
+pub enum git_signature {}
+pub enum git_mailmap {}
+
+extern {
+    // commit signatures
+    pub fn git_commit_extract_signature(signature: *mut git_buf,
+                                        signed_data: *mut git_buf,
+                                        repo: *mut git_repository,
+                                        commit_id: *mut git_oid,
+                                        field: *const c_char) -> c_int;
+    pub fn git_commit_create_buffer(out: *mut git_buf,
+                                    repo: *mut git_repository,
+                                    author: *const git_signature,
+                                    committer: *const git_signature,
+                                    message_encoding: *const c_char,
+                                    message: *const c_char,
+                                    tree: *const git_tree,
+                                    parent_count: size_t,
+                                    parents: *const *const git_commit) -> c_int;
+    pub fn git_commit_create_with_signature(out: *mut git_oid,
+                                            repo: *mut git_repository,
+                                            commit_content: *const c_char,
+                                            signature: *const c_char,
+                                            signature_field: *const c_char)
+                                            -> c_int;
+
+    // mailmap-resolved identities
+    pub fn git_commit_author_with_mailmap(out: *mut *mut git_signature,
+                                          commit: *const git_commit,
+                                          mailmap: *const git_mailmap) -> c_int;
+    pub fn git_commit_committer_with_mailmap(out: *mut *mut git_signature,
+                                             commit: *const git_commit,
+                                             mailmap: *const git_mailmap)
+                                             -> c_int;
+
+    // header fields and ancestry
+    pub fn git_commit_header_field(out: *mut git_buf,
+                                   commit: *const git_commit,
+                                   field: *const c_char) -> c_int;
+    pub fn git_commit_nth_gen_ancestor(ancestor: *mut *mut git_commit,
+                                       commit: *const git_commit,
+                                       n: c_uint) -> c_int;
+    pub fn git_commit_dup(out: *mut *mut git_commit,
+                          source: *mut git_commit) -> c_int;
+
+    // mailmap
+    pub fn git_mailmap_new(out: *mut *mut git_mailmap) -> c_int;
+    pub fn git_mailmap_free(mm: *mut git_mailmap);
+    pub fn git_mailmap_add_entry(mm: *mut git_mailmap,
+                                 real_name: *const c_char,
+                                 real_email: *const c_char,
+                                 replace_name: *const c_char,
+                                 replace_email: *const c_char) -> c_int;
+    pub fn git_mailmap_from_buffer(out: *mut *mut git_mailmap,
+                                   buf: *const c_char,
+                                   len: size_t) -> c_int;
+    pub fn git_mailmap_from_repository(out: *mut *mut git_mailmap,
+                                       repo: *mut git_repository) -> c_int;
+    pub fn git_mailmap_resolve(real_name: *mut *const c_char,
+                               real_email: *mut *const c_char,
+                               mm: *const git_mailmap,
+                               name: *const c_char,
+                               email: *const c_char) -> c_int;
+    pub fn git_mailmap_resolve_signature(out: *mut *mut git_signature,
+                                         mm: *const git_mailmap,
+                                         sig: *const git_signature) -> c_int;
+}
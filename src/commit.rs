@@ -1,9 +1,11 @@
 use std::iter::Range;
 use std::marker;
+use std::mem;
 use std::str;
 use libc;
 
-use {raw, signature, Oid, Error, Signature, Tree, Time, Object};
+use {raw, signature, Oid, Error, Signature, Tree, Time, Object, Buf};
+use {Mailmap};
 use util::Binding;
 
 /// A structure to represent a git [commit][1]
@@ -51,6 +53,37 @@ impl<'repo> Commit<'repo> {
     /// Get access to the underlying raw pointer.
     pub fn raw(&self) -> *mut raw::git_commit { self.raw }
 
+    /// Casts this `Commit` to be usable as an `Object`.
+    ///
+    /// The returned borrow shares the same underlying raw pointer, so no ODB
+    /// round-trip is performed.
+    pub fn as_object(&self) -> &Object<'repo> {
+        unsafe {
+            &*(self as *const _ as *const Object<'repo>)
+        }
+    }
+
+    /// Consumes this `Commit`, returning the underlying `Object`.
+    ///
+    /// Ownership of the raw pointer is transferred to the returned object, so
+    /// no double-free occurs.
+    pub fn into_object(self) -> Object<'repo> {
+        assert_eq!(mem::size_of_val(&self), mem::size_of::<Object>());
+        unsafe { mem::transmute(self) }
+    }
+
+    /// Create an owned, independent copy of this commit.
+    ///
+    /// This duplicates the in-memory commit object rather than re-reading it
+    /// from the ODB.
+    pub fn dup(&self) -> Result<Commit<'repo>, Error> {
+        let mut ret = 0 as *mut raw::git_commit;
+        unsafe {
+            try_call!(raw::git_commit_dup(&mut ret, self.raw));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Get the full message of a commit.
     ///
     /// The returned message will be slightly prettified by removing any
@@ -110,6 +143,30 @@ impl<'repo> Commit<'repo> {
         }
     }
 
+    /// Get an arbitrary header field of this commit.
+    ///
+    /// This returns just the value(s) of the named field, e.g. `encoding`,
+    /// `gpgsig`, or a custom field written by tooling, without having to parse
+    /// the whole `raw_header`. An error is returned if the field is absent.
+    pub fn header_field_bytes(&self, field: &str) -> Result<Buf, Error> {
+        let buf = Buf::new();
+        let field = try!(::std::ffi::CString::new(field));
+        unsafe {
+            try_call!(raw::git_commit_header_field(buf.raw(), &*self.raw, field));
+        }
+        Ok(buf)
+    }
+
+    /// Get an arbitrary header field of this commit as a string.
+    ///
+    /// This is a convenience wrapper around `header_field_bytes`. `None` is
+    /// returned if the value is not valid utf-8; an error is still returned
+    /// when the field itself is absent.
+    pub fn header_field(&self, field: &str) -> Result<Option<String>, Error> {
+        let buf = try!(self.header_field_bytes(field));
+        Ok(str::from_utf8(&buf).ok().map(|s| s.to_string()))
+    }
+
     /// Get the short "summary" of the git commit message.
     ///
     /// The returned message is the summary of the commit, comprising the first
@@ -177,6 +234,34 @@ impl<'repo> Commit<'repo> {
         }
     }
 
+    /// Get the author of this commit, resolved through a mailmap.
+    ///
+    /// The returned signature has its name and email canonicalized according to
+    /// `mailmap`; the plain `author` accessor returns the unmapped values.
+    pub fn author_with_mailmap(&self, mailmap: &Mailmap)
+                               -> Result<Signature<'static>, Error> {
+        let mut ret = 0 as *mut raw::git_signature;
+        unsafe {
+            try_call!(raw::git_commit_author_with_mailmap(&mut ret, &*self.raw,
+                                                          mailmap.raw()));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
+    /// Get the committer of this commit, resolved through a mailmap.
+    ///
+    /// The returned signature has its name and email canonicalized according to
+    /// `mailmap`; the plain `committer` accessor returns the unmapped values.
+    pub fn committer_with_mailmap(&self, mailmap: &Mailmap)
+                                  -> Result<Signature<'static>, Error> {
+        let mut ret = 0 as *mut raw::git_signature;
+        unsafe {
+            try_call!(raw::git_commit_committer_with_mailmap(&mut ret, &*self.raw,
+                                                             mailmap.raw()));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Amend this existing commit with all non-`None` values
     ///
     /// This creates a new commit that is exactly the same as the old commit,
@@ -220,6 +305,21 @@ impl<'repo> Commit<'repo> {
         }
     }
 
+    /// Get the nth generation ancestor of this commit, following the
+    /// first-parent chain.
+    ///
+    /// Passing `0` returns the commit itself, `1` is equivalent to
+    /// `parent(0)`, and so on. An error is returned if the first-parent chain
+    /// is shorter than `n` generations.
+    pub fn nth_gen_ancestor(&self, n: u32) -> Result<Commit<'repo>, Error> {
+        let mut ret = 0 as *mut raw::git_commit;
+        unsafe {
+            try_call!(raw::git_commit_nth_gen_ancestor(&mut ret, &*self.raw,
+                                                       n as libc::c_uint));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
     /// Get the specified parent id of the commit.
     ///
     /// This is different from `parent`, which will attemptstempt to load the
@@ -325,5 +425,29 @@ mod tests {
 
         repo.find_object(target, None).unwrap().as_commit().unwrap();
     }
+
+    #[test]
+    fn create_signed_roundtrip() {
+        let (_td, repo) = ::test::repo_init();
+        let head = repo.head().unwrap();
+        let parent = repo.find_commit(head.target().unwrap()).unwrap();
+        let sig = repo.signature().unwrap();
+        let tree = repo.find_tree(parent.tree_id()).unwrap();
+
+        let buf = repo.commit_create_buffer(&sig, &sig, "signed",
+                                            &tree, &[&parent]).unwrap();
+        let content = str::from_utf8(&buf).unwrap();
+        let fake = "-----BEGIN PGP SIGNATURE-----\n\nfake\n-----END PGP SIGNATURE-----\n";
+        let id = repo.commit_signed(content, fake, None).unwrap();
+
+        let (signature, signed) = repo.extract_signature(&id, None).unwrap();
+        let signature = str::from_utf8(&signature).unwrap();
+        let signed = str::from_utf8(&signed).unwrap();
+        // The signed payload is the buffer we handed in, verbatim.
+        assert_eq!(signed, content);
+        // Don't assume a particular trailing-whitespace rule for the stored
+        // signature; compare against the input modulo surrounding whitespace.
+        assert_eq!(signature.trim(), fake.trim());
+    }
 }
 
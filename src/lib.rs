@@ -0,0 +1,65 @@
+//! # libgit2 bindings for Rust
+//!
+//! This library contains bindings to the [libgit2][1] C library which is used
+//! to manage git repositories. The library itself is a work in progress and is
+//! likely lacking some bindings here and there, so be warned.
+//!
+//! [1]: https://libgit2.github.com/
+//!
+//! The git2-rs library strives to be as close to libgit2 as possible, but at
+//! the same time make the C library safe to use with respect to memory
+//! management and error handling in Rust.
+
+#![feature(unsafe_destructor)]
+
+extern crate libc;
+extern crate url;
+extern crate "libgit2-sys" as raw;
+
+use std::ffi::CString;
+use std::str;
+
+pub use commit::{Commit, Parents, ParentIds};
+pub use error::Error;
+pub use mailmap::Mailmap;
+pub use object::Object;
+pub use oid::Oid;
+pub use signature::Signature;
+pub use time::Time;
+pub use tree::Tree;
+
+mod commit;
+mod error;
+mod mailmap;
+mod object;
+mod oid;
+mod repo;
+mod signature;
+mod time;
+mod tree;
+mod util;
+
+/// Initialize openssl for the libgit2 library.
+fn init() {
+    static INIT: std::sync::Once = std::sync::ONCE_INIT;
+    INIT.call_once(|| unsafe {
+        raw::openssl_init();
+    });
+}
+
+unsafe fn opt_bytes<'a, T>(_anchor: &'a T,
+                           c: *const libc::c_char) -> Option<&'a [u8]> {
+    if c.is_null() {
+        None
+    } else {
+        let s = std::ffi::CStr::from_ptr(c);
+        Some(s.to_bytes())
+    }
+}
+
+fn opt_cstr(o: Option<&str>) -> Result<Option<CString>, Error> {
+    match o {
+        Some(s) => Ok(Some(try!(CString::new(s)))),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,108 @@
+use std::ffi::{CStr, CString};
+use libc;
+
+use {raw, Error, Signature};
+use util::Binding;
+
+/// A structure to represent a git [mailmap][1].
+///
+/// A mailmap maps author and committer identities onto a canonical name and
+/// email, collapsing the aliases a single contributor may have used over time.
+///
+/// [1]: https://git-scm.com/docs/gitmailmap
+pub struct Mailmap {
+    raw: *mut raw::git_mailmap,
+}
+
+impl Mailmap {
+    /// Create an empty, in-memory mailmap.
+    ///
+    /// Entries can be added with `add_entry`.
+    pub fn new() -> Result<Mailmap, Error> {
+        ::init();
+        let mut ret = 0 as *mut raw::git_mailmap;
+        unsafe {
+            try_call!(raw::git_mailmap_new(&mut ret));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
+    /// Create a mailmap by parsing the given buffer.
+    pub fn from_buffer(buf: &str) -> Result<Mailmap, Error> {
+        ::init();
+        let mut ret = 0 as *mut raw::git_mailmap;
+        unsafe {
+            try_call!(raw::git_mailmap_from_buffer(&mut ret,
+                                                   buf.as_ptr() as *const _,
+                                                   buf.len() as libc::size_t));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+
+    /// Add a single entry to this mailmap.
+    ///
+    /// Either the real name or the real email may be left `None`. The
+    /// `replace_email` to match is always required; `replace_name` may be
+    /// left `None`.
+    pub fn add_entry(&mut self,
+                     real_name: Option<&str>,
+                     real_email: Option<&str>,
+                     replace_name: Option<&str>,
+                     replace_email: &str) -> Result<(), Error> {
+        let real_name = try!(::opt_cstr(real_name));
+        let real_email = try!(::opt_cstr(real_email));
+        let replace_name = try!(::opt_cstr(replace_name));
+        let replace_email = try!(CString::new(replace_email));
+        unsafe {
+            try_call!(raw::git_mailmap_add_entry(self.raw, real_name, real_email,
+                                                 replace_name, replace_email));
+            Ok(())
+        }
+    }
+
+    /// Resolve a name and email through this mailmap.
+    ///
+    /// Returns the canonicalized `(name, email)` pair. If no entry matches the
+    /// input is echoed back unchanged.
+    pub fn resolve(&self, name: &str, email: &str)
+                   -> Result<(String, String), Error> {
+        let name = try!(CString::new(name));
+        let email = try!(CString::new(email));
+        let mut real_name = 0 as *const libc::c_char;
+        let mut real_email = 0 as *const libc::c_char;
+        unsafe {
+            try_call!(raw::git_mailmap_resolve(&mut real_name, &mut real_email,
+                                               self.raw, name, email));
+            let real_name = CStr::from_ptr(real_name).to_bytes();
+            let real_email = CStr::from_ptr(real_email).to_bytes();
+            Ok((String::from_utf8_lossy(real_name).into_owned(),
+                String::from_utf8_lossy(real_email).into_owned()))
+        }
+    }
+
+    /// Resolve a signature through this mailmap, returning a new owned
+    /// signature with the canonicalized name and email.
+    pub fn resolve_signature(&self, sig: &Signature)
+                             -> Result<Signature<'static>, Error> {
+        let mut ret = 0 as *mut raw::git_signature;
+        unsafe {
+            try_call!(raw::git_mailmap_resolve_signature(&mut ret, self.raw,
+                                                         sig.raw()));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+}
+
+impl Binding for Mailmap {
+    type Raw = *mut raw::git_mailmap;
+    unsafe fn from_raw(raw: *mut raw::git_mailmap) -> Mailmap {
+        Mailmap { raw: raw }
+    }
+    fn raw(&self) -> *mut raw::git_mailmap { self.raw }
+}
+
+impl Drop for Mailmap {
+    fn drop(&mut self) {
+        unsafe { raw::git_mailmap_free(self.raw) }
+    }
+}
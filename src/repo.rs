@@ -0,0 +1,99 @@
+use std::ffi::CString;
+use libc;
+
+use {raw, Oid, Error, Signature, Tree, Commit, Buf, Mailmap, Repository};
+use util::Binding;
+
+impl Repository {
+    /// Extract the signature from a commit.
+    ///
+    /// Given the `Oid` of a commit and the name of a header field, returns a
+    /// pair of buffers: the detached signature stored in `field` and the signed
+    /// payload, i.e. the commit content with the signature field removed. These
+    /// can then be handed to an external verifier such as gpgme or ssh-keygen.
+    ///
+    /// If `field` is `None` the `"gpgsig"` header is used. An error is returned
+    /// when the commit contains no such field, which lets callers distinguish
+    /// signed from unsigned commits.
+    pub fn extract_signature(&self, commit_id: &Oid, field: Option<&str>)
+                             -> Result<(Buf, Buf), Error> {
+        let signature = Buf::new();
+        let signed = Buf::new();
+        let field = try!(::opt_cstr(field));
+        unsafe {
+            try_call!(raw::git_commit_extract_signature(signature.raw(),
+                                                        signed.raw(),
+                                                        self.raw(),
+                                                        commit_id.raw() as *mut _,
+                                                        field));
+        }
+        Ok((signature, signed))
+    }
+
+    /// Create a commit object in canonical form without writing it to the ODB.
+    ///
+    /// This is the first half of creating a signed commit: the returned buffer
+    /// holds the exact content that would be hashed as the commit object. The
+    /// caller signs that content externally and then passes both back to
+    /// `commit_signed` to write the final, signed object.
+    pub fn commit_create_buffer(&self,
+                                author: &Signature,
+                                committer: &Signature,
+                                message: &str,
+                                tree: &Tree,
+                                parents: &[&Commit]) -> Result<Buf, Error> {
+        let buf = Buf::new();
+        let message = try!(CString::new(message));
+        let parent_ptrs = parents.iter().map(|p| {
+            p.raw() as *const raw::git_commit
+        }).collect::<Vec<_>>();
+        unsafe {
+            try_call!(raw::git_commit_create_buffer(buf.raw(),
+                                                    self.raw(),
+                                                    author.raw(),
+                                                    committer.raw(),
+                                                    0 as *const libc::c_char,
+                                                    message,
+                                                    tree.raw(),
+                                                    parents.len() as libc::size_t,
+                                                    parent_ptrs.as_ptr()));
+        }
+        Ok(buf)
+    }
+
+    /// Write a commit object, attaching the given signature.
+    ///
+    /// `commit_content` is the buffer produced by `commit_create_buffer` and
+    /// `signature` is the armored signature the caller computed over it. The
+    /// signature is stored in the `signature_field` header, defaulting to
+    /// `"gpgsig"` when `None`. Returns the `Oid` of the newly written object.
+    pub fn commit_signed(&self,
+                         commit_content: &str,
+                         signature: &str,
+                         signature_field: Option<&str>) -> Result<Oid, Error> {
+        let mut raw = raw::git_oid { id: [0; raw::GIT_OID_RAWSZ] };
+        let commit_content = try!(CString::new(commit_content));
+        let signature = try!(CString::new(signature));
+        let signature_field = try!(::opt_cstr(signature_field));
+        unsafe {
+            try_call!(raw::git_commit_create_with_signature(&mut raw,
+                                                            self.raw(),
+                                                            commit_content,
+                                                            signature,
+                                                            signature_field));
+            Ok(Binding::from_raw(&raw as *const _))
+        }
+    }
+
+    /// Load the mailmap for this repository.
+    ///
+    /// The mailmap is built from the repository's `.mailmap` file as well as
+    /// the `mailmap.file` and `mailmap.blob` configuration entries.
+    pub fn mailmap(&self) -> Result<Mailmap, Error> {
+        let mut ret = 0 as *mut raw::git_mailmap;
+        unsafe {
+            try_call!(raw::git_mailmap_from_repository(&mut ret, self.raw()));
+            Ok(Binding::from_raw(ret))
+        }
+    }
+}